@@ -1,14 +1,91 @@
-use crate::utils::occupiable::OccupiableState;
+use crate::utils::{
+    occupiable::{Occupiable, OccupiableError, OccupiableState},
+    state_machine::TransitionObserver,
+};
 
-#[derive(PartialEq, Debug, Default)]
+#[derive(Default)]
 pub struct Chair {
-    pub occupation_state: OccupiableState,
+    occupation_state: OccupiableState,
+    observers: Vec<Box<dyn TransitionObserver<OccupiableState>>>,
+}
+
+impl std::fmt::Debug for Chair {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Chair")
+            .field("occupation_state", &self.occupation_state)
+            .field("observers", &self.observers.len())
+            .finish()
+    }
 }
 
 impl Chair {
     pub fn new() -> Chair {
+        Chair::default()
+    }
+
+    pub fn builder() -> ChairBuilder {
+        ChairBuilder::default()
+    }
+
+    fn notify(&self, from: OccupiableState, to: OccupiableState, event: &str) {
+        for observer in &self.observers {
+            observer.on_transition(&from, &to, event);
+        }
+    }
+}
+
+impl Occupiable for Chair {
+    fn can_occupy(&self) -> bool {
+        self.occupation_state.can_occupy()
+    }
+
+    fn can_vacate(&self) -> bool {
+        self.occupation_state.can_vacate()
+    }
+
+    fn is_occupied(&self) -> bool {
+        self.occupation_state.is_occupied()
+    }
+
+    fn is_vacant(&self) -> bool {
+        self.occupation_state.is_vacant()
+    }
+
+    fn occupy(&mut self) -> Result<(), OccupiableError> {
+        let from = self.occupation_state;
+        self.occupation_state.occupy()?;
+        self.notify(from, self.occupation_state, "occupy");
+        Ok(())
+    }
+
+    fn vacate(&mut self) -> Result<(), OccupiableError> {
+        let from = self.occupation_state;
+        self.occupation_state.vacate()?;
+        self.notify(from, self.occupation_state, "vacate");
+        Ok(())
+    }
+}
+
+#[derive(Default)]
+pub struct ChairBuilder {
+    observers: Vec<Box<dyn TransitionObserver<OccupiableState>>>,
+}
+
+impl ChairBuilder {
+    pub fn new() -> ChairBuilder {
+        ChairBuilder::default()
+    }
+
+    /// Registers an observer notified after every successful occupy/vacate.
+    pub fn with_observer(mut self, observer: impl TransitionObserver<OccupiableState> + 'static) -> ChairBuilder {
+        self.observers.push(Box::new(observer));
+        self
+    }
+
+    pub fn build(self) -> Chair {
         Chair {
-            ..Default::default()
+            occupation_state: OccupiableState::default(),
+            observers: self.observers,
         }
     }
 }
@@ -17,24 +94,54 @@ impl Chair {
 mod tests {
 
     use super::*;
-    use crate::utils::occupiable::Occupiable;
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicU32, Ordering};
 
     #[test]
     fn vacant_by_default() {
         let c1 = Chair::default();
-        assert_eq!(c1.occupation_state.is_vacant(), true);
-        assert_eq!(c1.occupation_state.is_occupied(), false);
+        assert_eq!(c1.is_vacant(), true);
+        assert_eq!(c1.is_occupied(), false);
+    }
+
+    #[test]
+    fn can_occupy() {
+        let mut c1 = Chair::new();
+        assert_eq!(c1.occupy().is_ok(), true);
+        assert_eq!(c1.is_occupied(), true);
+    }
+
+    #[test]
+    fn can_vacate() {
+        let mut c1 = Chair::new();
+        c1.occupy().unwrap();
+
+        assert_eq!(c1.vacate().is_ok(), true);
+        assert_eq!(c1.is_vacant(), true);
     }
 
-    // #[test]
-    // fn can_occupy() {
-    //     let c1 = Chair::new();
-    //     assert_eq!(c1.occupy.ok(), Chair {});
-    // }
+    struct CountingObserver {
+        calls: Arc<AtomicU32>,
+    }
 
-    // #[test]
-    // fn can_vacate() {
-    //     let c1 = Chair::new();
-    //     assert_eq!(c1, Chair {});
-    // }
+    impl TransitionObserver<OccupiableState> for CountingObserver {
+        fn on_transition(&self, _from: &OccupiableState, _to: &OccupiableState, _event: &str) {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    #[test]
+    fn observer_fires_only_on_successful_transitions() {
+        let calls = Arc::new(AtomicU32::new(0));
+        let mut chair = Chair::builder()
+            .with_observer(CountingObserver { calls: calls.clone() })
+            .build();
+
+        assert_eq!(chair.occupy().is_ok(), true);
+        assert_eq!(chair.occupy().is_err(), true);
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+        assert_eq!(chair.vacate().is_ok(), true);
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
 }
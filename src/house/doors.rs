@@ -1,67 +1,133 @@
 use crate::utils::{
-    lockable::{Lockable, LockableError, LockableState},
+    lockable::{CodeLock, Lockable, LockableError, LockableState},
     openable::{Openable, OpenableError, OpenableState},
+    portal::{Portal, PortalState},
+    state_machine::TransitionObserver,
 };
 
-#[derive(PartialEq, Debug)]
+#[derive(Default)]
 pub struct Door {
-    open_state: OpenableState,
-    lock_state: LockableState,
+    portal: Portal,
+    code_lock: Option<CodeLock>,
+    open_observers: Vec<Box<dyn TransitionObserver<OpenableState>>>,
+    lock_observers: Vec<Box<dyn TransitionObserver<LockableState>>>,
+}
+
+impl std::fmt::Debug for Door {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Door")
+            .field("portal", &self.portal)
+            .field("code_lock", &self.code_lock)
+            .field("open_observers", &self.open_observers.len())
+            .field("lock_observers", &self.lock_observers.len())
+            .finish()
+    }
+}
+
+impl Door {
+    fn notify_open(&self, from: OpenableState, to: OpenableState, event: &str) {
+        for observer in &self.open_observers {
+            observer.on_transition(&from, &to, event);
+        }
+    }
+
+    fn notify_lock(&self, from: LockableState, to: LockableState, event: &str) {
+        for observer in &self.lock_observers {
+            observer.on_transition(&from, &to, event);
+        }
+    }
 }
 
 impl Openable for Door {
     fn can_open(&self) -> bool {
-        self.open_state.can_open()
+        self.portal.can_open()
     }
 
     fn can_close(&self) -> bool {
-        self.open_state.can_close()
+        self.portal.can_close()
     }
 
     fn is_open(&self) -> bool {
-        self.open_state.is_open()
+        self.portal.is_open()
     }
 
     fn is_closed(&self) -> bool {
-        self.open_state.is_closed()
+        self.portal.is_closed()
     }
 
     fn open(&mut self) -> Result<(), OpenableError> {
-        match self.unlock() {
-            Ok(()) => self.open_state.open(),
-            Err(_) => Err(OpenableError::CannotOpen),
+        if self.code_lock.as_ref().is_some_and(CodeLock::is_disabled) {
+            return Err(OpenableError::CannotOpen);
         }
+
+        let from = self.portal.open_state();
+        self.portal.open()?;
+        self.notify_open(from, self.portal.open_state(), "open");
+        Ok(())
     }
 
     fn close(&mut self) -> Result<(), OpenableError> {
-        self.open_state.close()
+        let from = self.portal.open_state();
+        self.portal.close()?;
+        self.notify_open(from, self.portal.open_state(), "close");
+        Ok(())
     }
 }
 
 impl Lockable for Door {
     fn is_locked(&self) -> bool {
-        self.lock_state.is_locked()
+        self.portal.is_locked()
     }
 
     fn is_unlocked(&self) -> bool {
-        self.lock_state.is_unlocked()
+        self.portal.is_unlocked()
     }
 
     fn can_lock(&self) -> bool {
-        self.lock_state.can_lock()
+        self.portal.can_lock()
     }
 
     fn can_unlock(&self) -> bool {
-        self.lock_state.can_unlock()
+        self.portal.can_unlock()
     }
 
     fn lock(&mut self) -> Result<(), LockableError> {
-        let _ = self.open_state.close();
-        self.lock_state.lock()
+        if self.code_lock.as_ref().is_some_and(CodeLock::is_disabled) {
+            return Err(LockableError::Disabled);
+        }
+
+        // Commit the portal transition first: it's the one that can fail
+        // (e.g. while `Open`), and the `CodeLock` must only re-arm once the
+        // portal is guaranteed to follow suit, or the two end up disagreeing
+        // about whether the door is actually locked.
+        let from = self.portal.lock_state();
+        self.portal.lock()?;
+
+        if let Some(code_lock) = self.code_lock.as_mut() {
+            code_lock
+                .re_lock()
+                .expect("not disabled (checked above) and portal.lock() just succeeded, so re_lock can't fail");
+        }
+
+        self.notify_lock(from, self.portal.lock_state(), "lock");
+        Ok(())
     }
 
     fn unlock(&mut self) -> Result<(), LockableError> {
-        self.lock_state.unlock()
+        // A code-protected door can only be unlocked via `unlock_with_code`;
+        // the parameterless `Lockable::unlock` can't supply the code it needs.
+        if let Some(code_lock) = &self.code_lock {
+            return Err(if code_lock.is_disabled() {
+                LockableError::Disabled
+            } else {
+                LockableError::CodeRequired
+            });
+        }
+
+        let from = self.portal.lock_state();
+        self.portal.unlock()?;
+        self.notify_lock(from, self.portal.lock_state(), "unlock");
+        Ok(())
     }
 }
 
@@ -69,13 +135,200 @@ impl Door {
     pub fn builder() -> DoorBuilder {
         DoorBuilder::default()
     }
+
+    /// Unlocks a code-protected door, delegating to its [`CodeLock`].
+    ///
+    /// Wrong attempts count toward the lock's lockout threshold. Returns
+    /// `LockableError::NoCodeLock` if the door has no code lock configured,
+    /// or `LockableError::Disabled` once the lock has tripped its lockout.
+    pub fn unlock_with_code(&mut self, attempt: &str) -> Result<(), LockableError> {
+        if self.code_lock.is_none() {
+            return Err(LockableError::NoCodeLock);
+        }
+        if !self.portal.can_unlock() {
+            return Err(LockableError::AlreadyUnlocked);
+        }
+
+        // The code is checked (and failed attempts counted) before the
+        // portal ever moves, and `can_unlock` above guarantees the portal
+        // commit below succeeds, so `code_lock` and `self.portal` can't end
+        // up disagreeing about whether the door is locked.
+        let code_lock = self.code_lock.as_mut().expect("checked above");
+        code_lock.unlock(attempt)?;
+
+        let from = self.portal.lock_state();
+        self.portal
+            .unlock()
+            .expect("portal.can_unlock() just confirmed this will succeed");
+        self.notify_lock(from, self.portal.lock_state(), "unlock");
+        Ok(())
+    }
 }
 
 #[derive(Default)]
-pub struct DoorBuilder {}
+pub struct DoorBuilder {
+    code: Option<String>,
+    max_attempts: Option<u8>,
+    open_observers: Vec<Box<dyn TransitionObserver<OpenableState>>>,
+    lock_observers: Vec<Box<dyn TransitionObserver<LockableState>>>,
+}
 
 impl DoorBuilder {
     pub fn new() -> DoorBuilder {
-        DoorBuilder {}
+        DoorBuilder::default()
+    }
+
+    /// Makes the built door code-protected, locked with `code`.
+    pub fn with_code(mut self, code: impl Into<String>) -> DoorBuilder {
+        self.code = Some(code.into());
+        self
+    }
+
+    /// Overrides the default failed-attempt lockout threshold, including
+    /// down to `0`. Only takes effect alongside [`DoorBuilder::with_code`].
+    pub fn with_max_attempts(mut self, max_attempts: u8) -> DoorBuilder {
+        self.max_attempts = Some(max_attempts);
+        self
+    }
+
+    /// Registers an observer notified after every successful open/close.
+    pub fn with_open_observer(mut self, observer: impl TransitionObserver<OpenableState> + 'static) -> DoorBuilder {
+        self.open_observers.push(Box::new(observer));
+        self
+    }
+
+    /// Registers an observer notified after every successful lock/unlock.
+    pub fn with_lock_observer(mut self, observer: impl TransitionObserver<LockableState> + 'static) -> DoorBuilder {
+        self.lock_observers.push(Box::new(observer));
+        self
+    }
+
+    pub fn build(self) -> Door {
+        let code_lock = self.code.map(|code| {
+            let mut code_lock = match self.max_attempts {
+                Some(max_attempts) => CodeLock::with_max_attempts(max_attempts),
+                None => CodeLock::new(),
+            };
+            code_lock
+                .lock(code)
+                .expect("a freshly built CodeLock can always be locked");
+            code_lock
+        });
+
+        let portal = if code_lock.is_some() {
+            Portal::new(PortalState::ClosedLocked)
+        } else {
+            Portal::default()
+        };
+
+        Door {
+            portal,
+            code_lock,
+            open_observers: self.open_observers,
+            lock_observers: self.lock_observers,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    #[test]
+    fn code_protected_door_requires_the_code_to_unlock() {
+        let mut door = Door::builder().with_code("1234").build();
+
+        assert_eq!(door.is_locked(), true);
+        assert_eq!(door.unlock_with_code("0000").is_err(), true);
+        assert_eq!(door.unlock_with_code("1234").is_ok(), true);
+        assert_eq!(door.is_locked(), false);
+    }
+
+    #[test]
+    fn code_protected_door_disables_after_max_attempts() {
+        let mut door = Door::builder().with_code("1234").with_max_attempts(2).build();
+
+        assert_eq!(door.unlock_with_code("0000").is_err(), true);
+        assert_eq!(
+            door.unlock_with_code("0000").unwrap_err(),
+            LockableError::Disabled
+        );
+        assert_eq!(door.unlock_with_code("1234").unwrap_err(), LockableError::Disabled);
+        assert_eq!(door.open().unwrap_err(), OpenableError::CannotOpen);
+    }
+
+    #[test]
+    fn cannot_lock_a_door_while_it_is_open() {
+        let mut door = Door::default();
+        door.open().unwrap();
+
+        assert_eq!(door.lock().unwrap_err(), LockableError::Open);
+    }
+
+    #[test]
+    fn plain_unlock_is_rejected_for_a_code_protected_door() {
+        let mut door = Door::builder().with_code("1234").build();
+
+        assert_eq!(door.unlock().unwrap_err(), LockableError::CodeRequired);
+    }
+
+    #[test]
+    fn lock_while_open_does_not_desync_the_code_lock() {
+        let mut door = Door::builder().with_code("1234").build();
+        door.unlock_with_code("1234").unwrap();
+        door.open().unwrap();
+
+        assert_eq!(door.lock().unwrap_err(), LockableError::Open);
+
+        door.close().unwrap();
+        assert_eq!(door.lock().is_ok(), true);
+        assert_eq!(door.unlock_with_code("1234").is_ok(), true);
+    }
+
+    #[test]
+    fn plain_lock_rearms_the_code_lock_for_a_code_protected_door() {
+        let mut door = Door::builder().with_code("1234").build();
+
+        door.unlock_with_code("1234").unwrap();
+        door.open().unwrap();
+        door.close().unwrap();
+
+        assert_eq!(door.lock().is_ok(), true);
+        assert_eq!(door.unlock_with_code("1234").is_ok(), true);
+    }
+
+    #[test]
+    fn with_max_attempts_zero_disables_on_the_first_wrong_code() {
+        let mut door = Door::builder().with_code("1234").with_max_attempts(0).build();
+
+        assert_eq!(door.unlock_with_code("0000").unwrap_err(), LockableError::Disabled);
+    }
+
+    struct CountingObserver {
+        calls: Arc<AtomicU32>,
+    }
+
+    impl TransitionObserver<LockableState> for CountingObserver {
+        fn on_transition(&self, _from: &LockableState, _to: &LockableState, _event: &str) {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    #[test]
+    fn lock_observer_fires_only_on_successful_transitions() {
+        let calls = Arc::new(AtomicU32::new(0));
+        let mut door = Door::builder()
+            .with_lock_observer(CountingObserver { calls: calls.clone() })
+            .build();
+
+        assert_eq!(door.lock().is_ok(), true);
+        assert_eq!(door.lock().is_err(), true);
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+        assert_eq!(door.unlock().is_ok(), true);
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
     }
 }
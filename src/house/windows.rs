@@ -1,13 +1,156 @@
-use crate::utils::lockable::LockableState;
+use crate::utils::{
+    lockable::{Lockable, LockableError, LockableState},
+    openable::{Openable, OpenableError, OpenableState},
+    portal::{Portal, PortalState},
+    state_machine::TransitionObserver,
+};
 
-#[derive(PartialEq, Debug, Default)]
+#[derive(Default)]
 pub struct Window {
-    pub state: LockableState,
+    portal: Portal,
+    open_observers: Vec<Box<dyn TransitionObserver<OpenableState>>>,
+    lock_observers: Vec<Box<dyn TransitionObserver<LockableState>>>,
+}
+
+impl std::fmt::Debug for Window {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Window")
+            .field("portal", &self.portal)
+            .field("open_observers", &self.open_observers.len())
+            .field("lock_observers", &self.lock_observers.len())
+            .finish()
+    }
 }
 
 impl Window {
-    pub fn new(state: LockableState) -> Window {
-        Window { state }
+    pub fn new(state: PortalState) -> Window {
+        Window {
+            portal: Portal::new(state),
+            ..Window::default()
+        }
+    }
+
+    pub fn builder() -> WindowBuilder {
+        WindowBuilder::default()
+    }
+
+    pub fn status(&self) -> PortalState {
+        self.portal.status()
+    }
+
+    fn notify_open(&self, from: OpenableState, to: OpenableState, event: &str) {
+        for observer in &self.open_observers {
+            observer.on_transition(&from, &to, event);
+        }
+    }
+
+    fn notify_lock(&self, from: LockableState, to: LockableState, event: &str) {
+        for observer in &self.lock_observers {
+            observer.on_transition(&from, &to, event);
+        }
+    }
+}
+
+impl Openable for Window {
+    fn can_open(&self) -> bool {
+        self.portal.can_open()
+    }
+
+    fn can_close(&self) -> bool {
+        self.portal.can_close()
+    }
+
+    fn is_open(&self) -> bool {
+        self.portal.is_open()
+    }
+
+    fn is_closed(&self) -> bool {
+        self.portal.is_closed()
+    }
+
+    fn open(&mut self) -> Result<(), OpenableError> {
+        let from = self.portal.open_state();
+        self.portal.open()?;
+        self.notify_open(from, self.portal.open_state(), "open");
+        Ok(())
+    }
+
+    fn close(&mut self) -> Result<(), OpenableError> {
+        let from = self.portal.open_state();
+        self.portal.close()?;
+        self.notify_open(from, self.portal.open_state(), "close");
+        Ok(())
+    }
+}
+
+impl Lockable for Window {
+    fn is_locked(&self) -> bool {
+        self.portal.is_locked()
+    }
+
+    fn is_unlocked(&self) -> bool {
+        self.portal.is_unlocked()
+    }
+
+    fn can_lock(&self) -> bool {
+        self.portal.can_lock()
+    }
+
+    fn can_unlock(&self) -> bool {
+        self.portal.can_unlock()
+    }
+
+    fn lock(&mut self) -> Result<(), LockableError> {
+        let from = self.portal.lock_state();
+        self.portal.lock()?;
+        self.notify_lock(from, self.portal.lock_state(), "lock");
+        Ok(())
+    }
+
+    fn unlock(&mut self) -> Result<(), LockableError> {
+        let from = self.portal.lock_state();
+        self.portal.unlock()?;
+        self.notify_lock(from, self.portal.lock_state(), "unlock");
+        Ok(())
+    }
+}
+
+#[derive(Default)]
+pub struct WindowBuilder {
+    state: Option<PortalState>,
+    open_observers: Vec<Box<dyn TransitionObserver<OpenableState>>>,
+    lock_observers: Vec<Box<dyn TransitionObserver<LockableState>>>,
+}
+
+impl WindowBuilder {
+    pub fn new() -> WindowBuilder {
+        WindowBuilder::default()
+    }
+
+    /// Sets the window's initial status. Defaults to closed and unlocked.
+    pub fn with_state(mut self, state: PortalState) -> WindowBuilder {
+        self.state = Some(state);
+        self
+    }
+
+    /// Registers an observer notified after every successful open/close.
+    pub fn with_open_observer(mut self, observer: impl TransitionObserver<OpenableState> + 'static) -> WindowBuilder {
+        self.open_observers.push(Box::new(observer));
+        self
+    }
+
+    /// Registers an observer notified after every successful lock/unlock.
+    pub fn with_lock_observer(mut self, observer: impl TransitionObserver<LockableState> + 'static) -> WindowBuilder {
+        self.lock_observers.push(Box::new(observer));
+        self
+    }
+
+    pub fn build(self) -> Window {
+        Window {
+            portal: self.state.map(Portal::new).unwrap_or_default(),
+            open_observers: self.open_observers,
+            lock_observers: self.lock_observers,
+        }
     }
 }
 
@@ -15,29 +158,67 @@ impl Window {
 mod tests {
 
     use super::*;
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicU32, Ordering};
 
     #[test]
     fn can_be_created() {
-        let w1 = Window::new(LockableState::Open);
-        let w2 = Window::new(LockableState::ClosedAndUnlocked);
-        let w3 = Window::new(LockableState::Locked);
-        assert_eq!(
-            w1,
-            Window {
-                state: LockableState::Open
-            }
-        );
-        assert_eq!(
-            w2,
-            Window {
-                state: LockableState::ClosedAndUnlocked
-            }
-        );
-        assert_eq!(
-            w3,
-            Window {
-                state: LockableState::Locked
-            }
-        );
+        let w1 = Window::new(PortalState::Open);
+        let w2 = Window::new(PortalState::ClosedUnlocked);
+        let w3 = Window::new(PortalState::ClosedLocked);
+
+        assert_eq!(w1.status(), PortalState::Open);
+        assert_eq!(w2.status(), PortalState::ClosedUnlocked);
+        assert_eq!(w3.status(), PortalState::ClosedLocked);
+    }
+
+    struct CountingOpenObserver {
+        calls: Arc<AtomicU32>,
+    }
+
+    impl TransitionObserver<OpenableState> for CountingOpenObserver {
+        fn on_transition(&self, _from: &OpenableState, _to: &OpenableState, _event: &str) {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    #[test]
+    fn open_observer_fires_only_on_successful_transitions() {
+        let calls = Arc::new(AtomicU32::new(0));
+        let mut window = Window::builder()
+            .with_open_observer(CountingOpenObserver { calls: calls.clone() })
+            .build();
+
+        assert_eq!(window.open().is_ok(), true);
+        assert_eq!(window.open().is_err(), true);
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+        assert_eq!(window.close().is_ok(), true);
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    struct CountingLockObserver {
+        calls: Arc<AtomicU32>,
+    }
+
+    impl TransitionObserver<LockableState> for CountingLockObserver {
+        fn on_transition(&self, _from: &LockableState, _to: &LockableState, _event: &str) {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    #[test]
+    fn lock_observer_fires_only_on_successful_transitions() {
+        let calls = Arc::new(AtomicU32::new(0));
+        let mut window = Window::builder()
+            .with_lock_observer(CountingLockObserver { calls: calls.clone() })
+            .build();
+
+        assert_eq!(window.lock().is_ok(), true);
+        assert_eq!(window.lock().is_err(), true);
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+        assert_eq!(window.unlock().is_ok(), true);
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
     }
 }
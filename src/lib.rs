@@ -0,0 +1,2 @@
+pub mod house;
+pub mod utils;
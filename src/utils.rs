@@ -0,0 +1,6 @@
+pub mod lockable;
+pub mod occupiable;
+pub mod openable;
+pub mod portal;
+pub mod shared;
+pub mod state_machine;
@@ -1,8 +1,14 @@
+use crate::utils::state_machine::{Edge, StateMachine, lookup};
+
 #[derive(PartialEq, Debug)]
 pub enum LockableError {
     AlreadyLocked,
     AlreadyUnlocked,
     Locked,
+    Open,
+    Disabled,
+    CodeRequired,
+    NoCodeLock,
 }
 
 impl std::error::Error for LockableError {}
@@ -12,6 +18,10 @@ impl std::fmt::Display for LockableError {
             LockableError::AlreadyLocked => write!(f, "This is already locked"),
             LockableError::AlreadyUnlocked => write!(f, "This is already unlocked"),
             LockableError::Locked => write!(f, "This is locked"),
+            LockableError::Open => write!(f, "This can't be locked because it's open"),
+            LockableError::Disabled => write!(f, "Too many failed attempts, this lock is disabled"),
+            LockableError::CodeRequired => write!(f, "This lock requires a code; use unlock_with_code instead"),
+            LockableError::NoCodeLock => write!(f, "This lock has no code configured"),
         }
     }
 }
@@ -25,13 +35,54 @@ pub trait Lockable {
     fn unlock(&mut self) -> Result<(), LockableError>;
 }
 
-#[derive(PartialEq, Debug, Default)]
+#[derive(PartialEq, Debug, Default, Clone, Copy)]
 pub enum LockableState {
     #[default]
     Unlocked,
     Locked,
 }
 
+/// The events a [`LockableState`] can be fed through [`StateMachine::transition`].
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum LockableEvent {
+    Lock,
+    Unlock,
+}
+
+const TRANSITIONS: &[Edge<LockableState, LockableEvent>] = &[
+    Edge {
+        from: LockableState::Unlocked,
+        event: LockableEvent::Lock,
+        to: LockableState::Locked,
+        guard: None,
+    },
+    Edge {
+        from: LockableState::Locked,
+        event: LockableEvent::Unlock,
+        to: LockableState::Unlocked,
+        guard: None,
+    },
+];
+
+impl StateMachine for LockableState {
+    type State = LockableState;
+    type Event = LockableEvent;
+    type Error = LockableError;
+
+    fn state(&self) -> &LockableState {
+        self
+    }
+
+    fn transition(&mut self, event: LockableEvent) -> Result<&LockableState, LockableError> {
+        let next = lookup(TRANSITIONS, self, &event).ok_or(match event {
+            LockableEvent::Lock => LockableError::AlreadyLocked,
+            LockableEvent::Unlock => LockableError::AlreadyUnlocked,
+        })?;
+        *self = next;
+        Ok(self)
+    }
+}
+
 impl Lockable for LockableState {
     fn is_locked(&self) -> bool {
         matches!(self, LockableState::Locked)
@@ -50,23 +101,102 @@ impl Lockable for LockableState {
     }
 
     fn lock(&mut self) -> Result<(), LockableError> {
-        match self {
-            LockableState::Unlocked => {
-                *self = LockableState::Locked;
-                Ok(())
-            }
-            _ => Err(LockableError::AlreadyLocked),
-        }
+        self.transition(LockableEvent::Lock).map(|_| ())
     }
 
     fn unlock(&mut self) -> Result<(), LockableError> {
-        match self {
-            LockableState::Locked => {
-                *self = LockableState::Unlocked;
-                Ok(())
+        self.transition(LockableEvent::Unlock).map(|_| ())
+    }
+}
+
+/// The number of consecutive wrong-code attempts a fresh [`CodeLock`]
+/// tolerates before it disables itself.
+const DEFAULT_MAX_ATTEMPTS: u8 = 3;
+
+/// A keypad-style lock that requires a secret code to unlock.
+///
+/// Wraps a plain [`LockableState`], but after `max_attempts` consecutive
+/// wrong codes it trips into a terminal disabled state — mirroring a real
+/// keypad that stops responding (and "calls the police") once it's been
+/// tampered with — rejecting every further `lock`/`unlock` call with
+/// [`LockableError::Disabled`].
+#[derive(PartialEq, Debug)]
+pub struct CodeLock {
+    state: LockableState,
+    code: Option<String>,
+    failed_attempts: u8,
+    max_attempts: u8,
+    disabled: bool,
+}
+
+impl Default for CodeLock {
+    fn default() -> CodeLock {
+        CodeLock {
+            state: LockableState::default(),
+            code: None,
+            failed_attempts: 0,
+            max_attempts: DEFAULT_MAX_ATTEMPTS,
+            disabled: false,
+        }
+    }
+}
+
+impl CodeLock {
+    pub fn new() -> CodeLock {
+        CodeLock::default()
+    }
+
+    pub fn with_max_attempts(max_attempts: u8) -> CodeLock {
+        CodeLock {
+            max_attempts,
+            ..CodeLock::default()
+        }
+    }
+
+    pub fn is_disabled(&self) -> bool {
+        self.disabled
+    }
+
+    /// Sets `code` as the unlock code and moves the lock to `Locked`.
+    pub fn lock(&mut self, code: impl Into<String>) -> Result<(), LockableError> {
+        if self.disabled {
+            return Err(LockableError::Disabled);
+        }
+
+        self.state.lock()?;
+        self.code = Some(code.into());
+        Ok(())
+    }
+
+    /// Re-locks using the code set by the last [`CodeLock::lock`] call, for
+    /// callers (e.g. `Door::lock`) that don't have the code on hand.
+    pub fn re_lock(&mut self) -> Result<(), LockableError> {
+        if self.disabled {
+            return Err(LockableError::Disabled);
+        }
+
+        self.state.lock()
+    }
+
+    /// Unlocks only if `attempt` matches the stored code. A mismatch counts
+    /// as a failed attempt; the `max_attempts`-th failure disables the lock.
+    pub fn unlock(&mut self, attempt: &str) -> Result<(), LockableError> {
+        if self.disabled {
+            return Err(LockableError::Disabled);
+        }
+
+        if self.code.as_deref() != Some(attempt) {
+            self.failed_attempts += 1;
+            if self.failed_attempts >= self.max_attempts {
+                self.disabled = true;
+                return Err(LockableError::Disabled);
             }
-            _ => Err(LockableError::AlreadyUnlocked),
+            return Err(LockableError::Locked);
         }
+
+        self.state.unlock()?;
+        self.failed_attempts = 0;
+        Ok(())
     }
 }
 
@@ -138,4 +268,59 @@ mod tests {
         assert_eq!(w1, LockableState::Unlocked);
         assert_eq!(w2, LockableState::Unlocked);
     }
+
+    #[test]
+    fn code_lock_locks_and_unlocks_with_the_right_code() {
+        let mut lock = CodeLock::new();
+
+        assert_eq!(lock.lock("1234").is_ok(), true);
+        assert_eq!(lock.unlock("1234").is_ok(), true);
+        assert_eq!(lock.is_disabled(), false);
+    }
+
+    #[test]
+    fn code_lock_rejects_the_wrong_code() {
+        let mut lock = CodeLock::new();
+        lock.lock("1234").unwrap();
+
+        assert_eq!(lock.unlock("0000").unwrap_err(), LockableError::Locked);
+        assert_eq!(lock.is_disabled(), false);
+    }
+
+    #[test]
+    fn code_lock_disables_after_max_attempts() {
+        let mut lock = CodeLock::with_max_attempts(3);
+        lock.lock("1234").unwrap();
+
+        assert_eq!(lock.unlock("0000").unwrap_err(), LockableError::Locked);
+        assert_eq!(lock.unlock("0000").unwrap_err(), LockableError::Locked);
+        assert_eq!(lock.unlock("0000").unwrap_err(), LockableError::Disabled);
+        assert_eq!(lock.is_disabled(), true);
+
+        assert_eq!(lock.unlock("1234").unwrap_err(), LockableError::Disabled);
+        assert_eq!(lock.lock("5678").unwrap_err(), LockableError::Disabled);
+    }
+
+    #[test]
+    fn code_lock_re_locks_with_the_remembered_code() {
+        let mut lock = CodeLock::new();
+        lock.lock("1234").unwrap();
+        lock.unlock("1234").unwrap();
+
+        assert_eq!(lock.re_lock().is_ok(), true);
+        assert_eq!(lock.unlock("1234").is_ok(), true);
+    }
+
+    #[test]
+    fn code_lock_resets_failed_attempts_on_success() {
+        let mut lock = CodeLock::with_max_attempts(2);
+        lock.lock("1234").unwrap();
+
+        assert_eq!(lock.unlock("0000").unwrap_err(), LockableError::Locked);
+        assert_eq!(lock.unlock("1234").is_ok(), true);
+
+        lock.lock("1234").unwrap();
+        assert_eq!(lock.unlock("0000").unwrap_err(), LockableError::Locked);
+        assert_eq!(lock.is_disabled(), false);
+    }
 }
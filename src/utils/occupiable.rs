@@ -1,3 +1,5 @@
+use crate::utils::state_machine::{Edge, StateMachine, lookup};
+
 #[derive(PartialEq, Debug)]
 pub enum OccupiableError {
     AlreadyOccupied,
@@ -23,13 +25,54 @@ pub trait Occupiable {
     fn vacate(&mut self) -> Result<(), OccupiableError>;
 }
 
-#[derive(PartialEq, Debug, Default)]
+#[derive(PartialEq, Debug, Default, Clone, Copy)]
 pub enum OccupiableState {
     #[default]
     Vacant,
     Occupied,
 }
 
+/// The events an [`OccupiableState`] can be fed through [`StateMachine::transition`].
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum OccupiableEvent {
+    Occupy,
+    Vacate,
+}
+
+const TRANSITIONS: &[Edge<OccupiableState, OccupiableEvent>] = &[
+    Edge {
+        from: OccupiableState::Vacant,
+        event: OccupiableEvent::Occupy,
+        to: OccupiableState::Occupied,
+        guard: None,
+    },
+    Edge {
+        from: OccupiableState::Occupied,
+        event: OccupiableEvent::Vacate,
+        to: OccupiableState::Vacant,
+        guard: None,
+    },
+];
+
+impl StateMachine for OccupiableState {
+    type State = OccupiableState;
+    type Event = OccupiableEvent;
+    type Error = OccupiableError;
+
+    fn state(&self) -> &OccupiableState {
+        self
+    }
+
+    fn transition(&mut self, event: OccupiableEvent) -> Result<&OccupiableState, OccupiableError> {
+        let next = lookup(TRANSITIONS, self, &event).ok_or(match event {
+            OccupiableEvent::Occupy => OccupiableError::AlreadyOccupied,
+            OccupiableEvent::Vacate => OccupiableError::AlreadyVacant,
+        })?;
+        *self = next;
+        Ok(self)
+    }
+}
+
 impl Occupiable for OccupiableState {
     fn can_occupy(&self) -> bool {
         matches!(&self, OccupiableState::Vacant)
@@ -48,23 +91,11 @@ impl Occupiable for OccupiableState {
     }
 
     fn occupy(&mut self) -> Result<(), OccupiableError> {
-        match &self {
-            OccupiableState::Occupied => Err(OccupiableError::AlreadyOccupied),
-            OccupiableState::Vacant => {
-                *self = OccupiableState::Occupied;
-                Ok(())
-            }
-        }
+        self.transition(OccupiableEvent::Occupy).map(|_| ())
     }
 
     fn vacate(&mut self) -> Result<(), OccupiableError> {
-        match &self {
-            OccupiableState::Vacant => Err(OccupiableError::AlreadyVacant),
-            OccupiableState::Occupied => {
-                *self = OccupiableState::Vacant;
-                Ok(())
-            }
-        }
+        self.transition(OccupiableEvent::Vacate).map(|_| ())
     }
 }
 
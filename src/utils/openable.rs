@@ -1,3 +1,5 @@
+use crate::utils::state_machine::{Edge, StateMachine, lookup};
+
 #[derive(PartialEq, Debug)]
 pub enum OpenableError {
     AlreadyClosed,
@@ -25,13 +27,54 @@ pub trait Openable {
     fn close(&mut self) -> Result<(), OpenableError>;
 }
 
-#[derive(PartialEq, Debug, Default)]
+#[derive(PartialEq, Debug, Default, Clone, Copy)]
 pub enum OpenableState {
     #[default]
     Closed,
     Open,
 }
 
+/// The events an [`OpenableState`] can be fed through [`StateMachine::transition`].
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum OpenableEvent {
+    Open,
+    Close,
+}
+
+const TRANSITIONS: &[Edge<OpenableState, OpenableEvent>] = &[
+    Edge {
+        from: OpenableState::Closed,
+        event: OpenableEvent::Open,
+        to: OpenableState::Open,
+        guard: None,
+    },
+    Edge {
+        from: OpenableState::Open,
+        event: OpenableEvent::Close,
+        to: OpenableState::Closed,
+        guard: None,
+    },
+];
+
+impl StateMachine for OpenableState {
+    type State = OpenableState;
+    type Event = OpenableEvent;
+    type Error = OpenableError;
+
+    fn state(&self) -> &OpenableState {
+        self
+    }
+
+    fn transition(&mut self, event: OpenableEvent) -> Result<&OpenableState, OpenableError> {
+        let next = lookup(TRANSITIONS, self, &event).ok_or(match event {
+            OpenableEvent::Open => OpenableError::AlreadyOpen,
+            OpenableEvent::Close => OpenableError::AlreadyClosed,
+        })?;
+        *self = next;
+        Ok(self)
+    }
+}
+
 impl Openable for OpenableState {
     fn is_open(&self) -> bool {
         matches!(self, OpenableState::Open)
@@ -49,23 +92,11 @@ impl Openable for OpenableState {
     }
 
     fn close(&mut self) -> Result<(), OpenableError> {
-        match self {
-            OpenableState::Open => {
-                *self = OpenableState::Closed;
-                Ok(())
-            }
-            _ => Err(OpenableError::AlreadyClosed),
-        }
+        self.transition(OpenableEvent::Close).map(|_| ())
     }
 
     fn open(&mut self) -> Result<(), OpenableError> {
-        match self {
-            OpenableState::Open => Err(OpenableError::AlreadyOpen),
-            OpenableState::Closed => {
-                *self = OpenableState::Open;
-                Ok(())
-            }
-        }
+        self.transition(OpenableEvent::Open).map(|_| ())
     }
 }
 
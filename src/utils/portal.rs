@@ -0,0 +1,153 @@
+use crate::utils::{
+    lockable::{Lockable, LockableError, LockableState},
+    openable::{Openable, OpenableError, OpenableState},
+};
+
+/// The combined open/locked status of a [`Portal`].
+///
+/// A single coherent projection of `Portal`'s two underlying states, so
+/// callers that only care about "what state is this door/window in" don't
+/// need to juggle an `OpenableState` and a `LockableState` separately.
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum PortalState {
+    Open,
+    ClosedUnlocked,
+    ClosedLocked,
+}
+
+/// Composes [`OpenableState`] and [`LockableState`] into one portal — a
+/// door, window, or anything else that can be opened and locked — with the
+/// cross-state rules a real one needs: it can't be locked while open, and
+/// it can't be opened while locked (unlock it first).
+#[derive(PartialEq, Debug, Default, Clone, Copy)]
+pub struct Portal {
+    open_state: OpenableState,
+    lock_state: LockableState,
+}
+
+impl Portal {
+    pub fn new(state: PortalState) -> Portal {
+        let (open_state, lock_state) = match state {
+            PortalState::Open => (OpenableState::Open, LockableState::Unlocked),
+            PortalState::ClosedUnlocked => (OpenableState::Closed, LockableState::Unlocked),
+            PortalState::ClosedLocked => (OpenableState::Closed, LockableState::Locked),
+        };
+        Portal { open_state, lock_state }
+    }
+
+    pub fn status(&self) -> PortalState {
+        if self.open_state.is_open() {
+            PortalState::Open
+        } else if self.lock_state.is_locked() {
+            PortalState::ClosedLocked
+        } else {
+            PortalState::ClosedUnlocked
+        }
+    }
+
+    pub fn open_state(&self) -> OpenableState {
+        self.open_state
+    }
+
+    pub fn lock_state(&self) -> LockableState {
+        self.lock_state
+    }
+}
+
+impl Openable for Portal {
+    fn can_open(&self) -> bool {
+        self.open_state.can_open() && self.lock_state.is_unlocked()
+    }
+
+    fn can_close(&self) -> bool {
+        self.open_state.can_close()
+    }
+
+    fn is_open(&self) -> bool {
+        self.open_state.is_open()
+    }
+
+    fn is_closed(&self) -> bool {
+        self.open_state.is_closed()
+    }
+
+    fn open(&mut self) -> Result<(), OpenableError> {
+        if self.lock_state.is_locked() {
+            return Err(OpenableError::CannotOpen);
+        }
+        self.open_state.open()
+    }
+
+    fn close(&mut self) -> Result<(), OpenableError> {
+        self.open_state.close()
+    }
+}
+
+impl Lockable for Portal {
+    fn is_locked(&self) -> bool {
+        self.lock_state.is_locked()
+    }
+
+    fn is_unlocked(&self) -> bool {
+        self.lock_state.is_unlocked()
+    }
+
+    fn can_lock(&self) -> bool {
+        self.lock_state.can_lock() && self.open_state.is_closed()
+    }
+
+    fn can_unlock(&self) -> bool {
+        self.lock_state.can_unlock()
+    }
+
+    fn lock(&mut self) -> Result<(), LockableError> {
+        if self.open_state.is_open() {
+            return Err(LockableError::Open);
+        }
+        self.lock_state.lock()
+    }
+
+    fn unlock(&mut self) -> Result<(), LockableError> {
+        self.lock_state.unlock()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn closed_and_unlocked_by_default() {
+        let portal = Portal::default();
+        assert_eq!(portal.status(), PortalState::ClosedUnlocked);
+    }
+
+    #[test]
+    fn new_builds_the_requested_status() {
+        assert_eq!(Portal::new(PortalState::Open).status(), PortalState::Open);
+        assert_eq!(Portal::new(PortalState::ClosedUnlocked).status(), PortalState::ClosedUnlocked);
+        assert_eq!(Portal::new(PortalState::ClosedLocked).status(), PortalState::ClosedLocked);
+    }
+
+    #[test]
+    fn cannot_lock_while_open() {
+        let mut portal = Portal::new(PortalState::Open);
+        assert_eq!(portal.lock().unwrap_err(), LockableError::Open);
+    }
+
+    #[test]
+    fn cannot_open_while_locked() {
+        let mut portal = Portal::new(PortalState::ClosedLocked);
+        assert_eq!(portal.open().unwrap_err(), OpenableError::CannotOpen);
+    }
+
+    #[test]
+    fn unlock_then_open_sequencing() {
+        let mut portal = Portal::new(PortalState::ClosedLocked);
+
+        assert_eq!(portal.open().is_err(), true);
+        assert_eq!(portal.unlock().is_ok(), true);
+        assert_eq!(portal.open().is_ok(), true);
+        assert_eq!(portal.status(), PortalState::Open);
+    }
+}
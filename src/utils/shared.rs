@@ -0,0 +1,162 @@
+use std::sync::Mutex;
+
+use crate::utils::{
+    lockable::{Lockable, LockableError},
+    occupiable::{Occupiable, OccupiableError},
+    openable::{Openable, OpenableError},
+};
+
+/// Shares a single `T` safely across threads.
+///
+/// Wraps `T` behind a `std::sync::Mutex` so a `can_*()` check and the state
+/// write it guards happen under one held lock. Two threads racing to
+/// `occupy()` the same chair, or `lock()` the same door, can't both
+/// succeed — the loser gets back the usual `*Error` variant instead of
+/// corrupting the shared state.
+///
+/// Always backed by `std::sync::Mutex`; there is no `parking_lot`-backed
+/// variant and no feature flag to choose between lock implementations.
+pub struct Shared<T> {
+    inner: Mutex<T>,
+}
+
+impl<T> Shared<T> {
+    pub fn new(value: T) -> Shared<T> {
+        Shared { inner: Mutex::new(value) }
+    }
+
+    fn with_lock<R>(&self, f: impl FnOnce(&mut T) -> R) -> R {
+        let mut guard = self.inner.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        f(&mut guard)
+    }
+}
+
+impl<T: Openable> Shared<T> {
+    pub fn can_open(&self) -> bool {
+        self.with_lock(|t| t.can_open())
+    }
+
+    pub fn can_close(&self) -> bool {
+        self.with_lock(|t| t.can_close())
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.with_lock(|t| t.is_open())
+    }
+
+    pub fn is_closed(&self) -> bool {
+        self.with_lock(|t| t.is_closed())
+    }
+
+    pub fn open(&self) -> Result<(), OpenableError> {
+        self.with_lock(|t| t.open())
+    }
+
+    pub fn close(&self) -> Result<(), OpenableError> {
+        self.with_lock(|t| t.close())
+    }
+}
+
+impl<T: Lockable> Shared<T> {
+    pub fn is_locked(&self) -> bool {
+        self.with_lock(|t| t.is_locked())
+    }
+
+    pub fn is_unlocked(&self) -> bool {
+        self.with_lock(|t| t.is_unlocked())
+    }
+
+    pub fn can_lock(&self) -> bool {
+        self.with_lock(|t| t.can_lock())
+    }
+
+    pub fn can_unlock(&self) -> bool {
+        self.with_lock(|t| t.can_unlock())
+    }
+
+    pub fn lock(&self) -> Result<(), LockableError> {
+        self.with_lock(|t| t.lock())
+    }
+
+    pub fn unlock(&self) -> Result<(), LockableError> {
+        self.with_lock(|t| t.unlock())
+    }
+}
+
+impl<T: Occupiable> Shared<T> {
+    pub fn can_occupy(&self) -> bool {
+        self.with_lock(|t| t.can_occupy())
+    }
+
+    pub fn can_vacate(&self) -> bool {
+        self.with_lock(|t| t.can_vacate())
+    }
+
+    pub fn is_occupied(&self) -> bool {
+        self.with_lock(|t| t.is_occupied())
+    }
+
+    pub fn is_vacant(&self) -> bool {
+        self.with_lock(|t| t.is_vacant())
+    }
+
+    pub fn occupy(&self) -> Result<(), OccupiableError> {
+        self.with_lock(|t| t.occupy())
+    }
+
+    pub fn vacate(&self) -> Result<(), OccupiableError> {
+        self.with_lock(|t| t.vacate())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::house::chairs::Chair;
+    use crate::utils::occupiable::OccupiableState;
+    use std::sync::Arc;
+    use std::thread;
+
+    #[test]
+    fn only_one_thread_can_occupy_a_shared_vacant_occupiable_state() {
+        let state = Arc::new(Shared::new(OccupiableState::default()));
+
+        let handles: Vec<_> = (0..8)
+            .map(|_| {
+                let state = Arc::clone(&state);
+                thread::spawn(move || state.occupy().is_ok())
+            })
+            .collect();
+
+        let successes = handles.into_iter().map(|h| h.join().unwrap()).filter(|ok| *ok).count();
+
+        assert_eq!(successes, 1);
+        assert_eq!(state.is_occupied(), true);
+    }
+
+    #[test]
+    fn only_one_thread_can_occupy_a_shared_vacant_chair() {
+        let chair = Arc::new(Shared::new(Chair::new()));
+
+        let handles: Vec<_> = (0..8)
+            .map(|_| {
+                let chair = Arc::clone(&chair);
+                thread::spawn(move || chair.occupy().is_ok())
+            })
+            .collect();
+
+        let successes = handles.into_iter().map(|h| h.join().unwrap()).filter(|ok| *ok).count();
+
+        assert_eq!(successes, 1);
+        assert_eq!(chair.is_occupied(), true);
+    }
+
+    #[test]
+    fn vacate_then_occupy_round_trips() {
+        let chair = Shared::new(OccupiableState::default());
+
+        assert_eq!(chair.occupy().is_ok(), true);
+        assert_eq!(chair.vacate().is_ok(), true);
+        assert_eq!(chair.is_vacant(), true);
+    }
+}
@@ -0,0 +1,127 @@
+/// A minimal event-driven finite state machine.
+///
+/// Implementors route every mutation through `transition` instead of
+/// hand-rolling a `match self.state { ... }` block, so the allowed
+/// `(State, Event) -> State` edges live in one auditable table per machine.
+pub trait StateMachine {
+    type State;
+    type Event;
+    type Error;
+
+    fn state(&self) -> &Self::State;
+    fn transition(&mut self, event: Self::Event) -> Result<&Self::State, Self::Error>;
+}
+
+/// One allowed `(State, Event) -> State` edge in a transition table.
+///
+/// `guard`, if present, is called with the current state and must also
+/// return `true` for the edge to be taken; when it returns `false`, the
+/// next edge sharing that `(from, event)` pair (if any) is tried instead.
+pub struct Edge<S, E> {
+    pub from: S,
+    pub event: E,
+    pub to: S,
+    pub guard: Option<fn(&S) -> bool>,
+}
+
+/// Finds the first edge in `table` matching `current` and `event` whose
+/// guard (if any) passes, returning the state it leads to.
+///
+/// Machines call this from their `transition` impl and turn a `None` into
+/// their own typed error for the missing edge.
+pub fn lookup<S, E>(table: &[Edge<S, E>], current: &S, event: &E) -> Option<S>
+where
+    S: PartialEq + Clone,
+    E: PartialEq,
+{
+    table
+        .iter()
+        .find(|edge| &edge.from == current && &edge.event == event && edge.guard.is_none_or(|guard| guard(current)))
+        .map(|edge| edge.to.clone())
+}
+
+/// Observes successful state transitions on an entity.
+///
+/// Register one with an entity's builder (e.g. `DoorBuilder::with_lock_observer`)
+/// to trigger side effects — logging, notifications, metrics — whenever a
+/// transition commits. Never called for a transition that returned `Err`.
+///
+/// Bound by `Send + Sync` so a `Box<dyn TransitionObserver<S>>` doesn't
+/// strand the entity holding it on its own thread — entities with observers
+/// (`Door`, `Window`, `Chair`) still need to cross into a [`crate::utils::shared::Shared`].
+pub trait TransitionObserver<S>: Send + Sync {
+    fn on_transition(&self, from: &S, to: &S, event: &str);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    #[derive(PartialEq, Debug, Clone, Copy)]
+    enum Light {
+        Red,
+        Green,
+    }
+
+    #[derive(PartialEq, Debug, Clone, Copy)]
+    enum Flip {
+        Toggle,
+    }
+
+    #[test]
+    fn finds_matching_edge() {
+        let table = [Edge {
+            from: Light::Red,
+            event: Flip::Toggle,
+            to: Light::Green,
+            guard: None,
+        }];
+
+        assert_eq!(lookup(&table, &Light::Red, &Flip::Toggle), Some(Light::Green));
+    }
+
+    #[test]
+    fn returns_none_without_a_matching_edge() {
+        let table = [Edge {
+            from: Light::Red,
+            event: Flip::Toggle,
+            to: Light::Green,
+            guard: None,
+        }];
+
+        assert_eq!(lookup(&table, &Light::Green, &Flip::Toggle), None);
+    }
+
+    #[test]
+    fn respects_a_failing_guard() {
+        let table = [Edge {
+            from: Light::Red,
+            event: Flip::Toggle,
+            to: Light::Green,
+            guard: Some(|_| false),
+        }];
+
+        assert_eq!(lookup(&table, &Light::Red, &Flip::Toggle), None);
+    }
+
+    struct RecordingObserver<'a> {
+        calls: &'a Mutex<Vec<(Light, Light, String)>>,
+    }
+
+    impl TransitionObserver<Light> for RecordingObserver<'_> {
+        fn on_transition(&self, from: &Light, to: &Light, event: &str) {
+            self.calls.lock().unwrap().push((*from, *to, event.to_string()));
+        }
+    }
+
+    #[test]
+    fn observer_receives_from_and_to_on_a_successful_transition() {
+        let calls = Mutex::new(Vec::new());
+        let observer = RecordingObserver { calls: &calls };
+
+        observer.on_transition(&Light::Red, &Light::Green, "toggle");
+
+        assert_eq!(calls.lock().unwrap().as_slice(), [(Light::Red, Light::Green, "toggle".to_string())]);
+    }
+}